@@ -1,4 +1,7 @@
+use std::time::Instant;
+
 use crate::config::PAGIConfig;
+use crate::error::PagiError;
 
 /// Placeholder Jira client.
 #[derive(Debug, Clone)]
@@ -12,19 +15,26 @@ impl JiraClient {
     }
 
     /// Simulates creating a Jira issue.
-    pub async fn create_issue(&self, summary: &str) -> Result<(), String> {
-        if self.config.jira_api_token.is_empty() {
-            return Err("JIRA_API_TOKEN is not set".to_string());
-        }
-
-        // Simulated external API call.
-        let _ = (
-            &self.config.jira_base_url,
-            &self.config.jira_api_token,
-            summary,
-        );
+    #[tracing::instrument(name = "jira_client.create_issue", skip(self, summary))]
+    pub async fn create_issue(&self, summary: &str) -> Result<(), PagiError> {
+        let started = Instant::now();
+        let result = (|| {
+            if self.config.jira_api_token.is_empty() {
+                return Err(PagiError::MissingEnv("JIRA_API_TOKEN"));
+            }
+
+            // Simulated external API call.
+            let _ = (
+                &self.config.jira_base_url,
+                &self.config.jira_api_token,
+                summary,
+            );
+
+            Ok(())
+        })();
 
-        Ok(())
+        crate::metrics::record_call("jira", "create_issue", started.elapsed(), result.is_ok());
+        result
     }
 }
 
@@ -40,18 +50,30 @@ impl CrowdstrikeClient {
     }
 
     /// Simulates isolating a host.
-    pub async fn isolate_host(&self, hostname: &str) -> Result<(), String> {
-        if self.config.crowdstrike_api_token.is_empty() {
-            return Err("CROWDSTRIKE_API_TOKEN is not set".to_string());
-        }
-
-        // Simulated external API call.
-        let _ = (
-            &self.config.crowdstrike_base_url,
-            &self.config.crowdstrike_api_token,
-            hostname,
-        );
+    #[tracing::instrument(name = "crowdstrike_client.isolate_host", skip(self, hostname))]
+    pub async fn isolate_host(&self, hostname: &str) -> Result<(), PagiError> {
+        let started = Instant::now();
+        let result = (|| {
+            if self.config.crowdstrike_api_token.is_empty() {
+                return Err(PagiError::MissingEnv("CROWDSTRIKE_API_TOKEN"));
+            }
+
+            // Simulated external API call.
+            let _ = (
+                &self.config.crowdstrike_base_url,
+                &self.config.crowdstrike_api_token,
+                hostname,
+            );
 
-        Ok(())
+            Ok(())
+        })();
+
+        crate::metrics::record_call(
+            "crowdstrike",
+            "isolate_host",
+            started.elapsed(),
+            result.is_ok(),
+        );
+        result
     }
 }
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use super::CommonClientConfig;
+
+pub const DEFAULT_API_BASE: &str = "https://api.anthropic.com/v1";
+pub const DEFAULT_ANTHROPIC_VERSION: &str = "2023-06-01";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnthropicConfig {
+    #[serde(flatten)]
+    pub common: CommonClientConfig,
+    #[serde(default = "default_anthropic_version")]
+    pub anthropic_version: String,
+}
+
+fn default_anthropic_version() -> String {
+    DEFAULT_ANTHROPIC_VERSION.to_string()
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use super::CommonClientConfig;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AzureOpenAiConfig {
+    #[serde(flatten)]
+    pub common: CommonClientConfig,
+    /// Azure deployment name, e.g. `gpt-4o-mini-prod`. Azure routes by
+    /// deployment rather than by OpenAI model id.
+    pub deployment: String,
+    /// API version query parameter Azure requires on every request.
+    #[serde(default = "default_api_version")]
+    pub api_version: String,
+}
+
+fn default_api_version() -> String {
+    "2024-06-01".to_string()
+}
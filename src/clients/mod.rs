@@ -0,0 +1,73 @@
+//! Per-provider client configuration, registered via [`register_client!`].
+//!
+//! Adding a new LLM backend is a two-step process: write a module holding its
+//! config struct, then add one line to the `register_client!` invocation
+//! below. The tagged `ClientConfig` enum and its `name()`/`common()`
+//! accessors are generated; `LLMProvider`'s request dispatch matches on every
+//! variant and needs an arm added for the new backend.
+
+pub mod anthropic;
+pub mod azure_openai;
+pub mod openai;
+pub mod openrouter;
+
+use serde::{Deserialize, Serialize};
+
+/// Declares the set of supported LLM backends.
+///
+/// Each entry is `(VariantName, module, ConfigStruct, "serde tag")`. This
+/// expands to a `#[serde(tag = "type")]` enum plus a `name()` accessor, so a
+/// `ClientConfig` can be deserialized straight from a tagged JSON/TOML/YAML
+/// value (e.g. `{ "type": "openrouter", "api_key": "..." }`) and matched back
+/// to the backend that produced it.
+macro_rules! register_client {
+    ($(($variant:ident, $module:ident, $config:ident, $tag:literal)),+ $(,)?) => {
+        /// Configuration for one configured LLM backend, tagged by `type`.
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        #[serde(tag = "type")]
+        pub enum ClientConfig {
+            $(
+                #[serde(rename = $tag)]
+                $variant($module::$config),
+            )+
+        }
+
+        impl ClientConfig {
+            /// The backend's registered name, matching its serde tag.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(ClientConfig::$variant(_) => $tag,)+
+                }
+            }
+
+            /// Shared fields common to every backend.
+            pub fn common(&self) -> &CommonClientConfig {
+                match self {
+                    $(ClientConfig::$variant(c) => &c.common,)+
+                }
+            }
+        }
+    };
+}
+
+register_client! {
+    (OpenAi, openai, OpenAiConfig, "openai"),
+    (OpenRouter, openrouter, OpenRouterConfig, "openrouter"),
+    (AzureOpenAi, azure_openai, AzureOpenAiConfig, "azure-openai"),
+    (Anthropic, anthropic, AnthropicConfig, "anthropic"),
+}
+
+/// Fields every backend config carries, regardless of provider-specific
+/// extras.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommonClientConfig {
+    pub api_key: String,
+    #[serde(default)]
+    pub api_base: Option<String>,
+    #[serde(default)]
+    pub models: Option<Vec<String>>,
+    /// Provider-specific knobs that don't warrant a dedicated field yet
+    /// (e.g. `organization`).
+    #[serde(flatten, default)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
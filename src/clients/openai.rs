@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use super::CommonClientConfig;
+
+pub const DEFAULT_API_BASE: &str = "https://api.openai.com/v1";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenAiConfig {
+    #[serde(flatten)]
+    pub common: CommonClientConfig,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use super::CommonClientConfig;
+
+pub const DEFAULT_API_BASE: &str = "https://openrouter.ai/api/v1";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenRouterConfig {
+    #[serde(flatten)]
+    pub common: CommonClientConfig,
+}
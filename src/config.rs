@@ -1,53 +1,319 @@
-use std::env;
+use std::path::Path;
+use std::{env, fs};
+
+use serde::{Deserialize, Serialize};
+
+use crate::clients::{openrouter::OpenRouterConfig, ClientConfig, CommonClientConfig};
+use crate::error::PagiError;
+
+/// Default location `load()` reads from (and writes a starter config to, if
+/// missing), relative to the process's working directory.
+pub const DEFAULT_CONFIG_PATH: &str = "pagi.toml";
 
 /// Secure configuration for all external providers.
 ///
 /// NOTE: This struct intentionally keeps values as `String` to avoid accidental
 /// lifetime issues and to make it easy to pass into clients.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PAGIConfig {
-    pub openrouter_api_key: String,
-    pub default_model: String,
+    /// Every LLM backend this process knows how to talk to.
+    #[serde(default)]
+    pub clients: Vec<ClientConfig>,
+    /// `name()` of the [`ClientConfig`] in `clients` that `LLMProvider`
+    /// dispatches to.
+    #[serde(default = "default_active_client")]
+    pub active_client: String,
 
     // Placeholder fields for other external integrations.
+    #[serde(default)]
     pub jira_api_token: String,
+    #[serde(default = "default_jira_base_url")]
     pub jira_base_url: String,
 
+    #[serde(default)]
     pub crowdstrike_api_token: String,
+    #[serde(default = "default_crowdstrike_base_url")]
     pub crowdstrike_base_url: String,
+
+    /// Outbound proxy URL (`https://` or `socks5://`) used for every
+    /// external HTTP call, or `None` to connect directly.
+    #[serde(default)]
+    pub proxy: Option<String>,
+    /// TCP connect timeout, in seconds, applied per request.
+    #[serde(default = "default_connect_timeout_secs")]
+    pub connect_timeout_secs: u64,
+    /// Overall request timeout, in seconds, applied per request.
+    #[serde(default = "default_request_timeout_secs")]
+    pub request_timeout_secs: u64,
+    /// Maximum number of retries for requests that fail with a transient
+    /// (429 or 5xx) status.
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+
+    /// Secret used to mint and verify bearer tokens for the optional
+    /// `server` gateway. Only required when that subsystem is enabled.
+    #[serde(default)]
+    pub llm_api_secret: Option<String>,
+}
+
+fn default_active_client() -> String {
+    "openrouter".to_string()
+}
+
+fn default_jira_base_url() -> String {
+    "https://jira.example.com".to_string()
+}
+
+fn default_crowdstrike_base_url() -> String {
+    "https://api.crowdstrike.com".to_string()
+}
+
+fn default_connect_timeout_secs() -> u64 {
+    10
+}
+
+fn default_request_timeout_secs() -> u64 {
+    60
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+impl Default for PAGIConfig {
+    fn default() -> Self {
+        PAGIConfig {
+            clients: vec![ClientConfig::OpenRouter(OpenRouterConfig {
+                common: CommonClientConfig {
+                    api_key: String::new(),
+                    api_base: None,
+                    models: Some(vec!["openai/gpt-4o-mini".to_string()]),
+                    extra: Default::default(),
+                },
+            })],
+            active_client: default_active_client(),
+            jira_api_token: String::new(),
+            jira_base_url: default_jira_base_url(),
+            crowdstrike_api_token: String::new(),
+            crowdstrike_base_url: default_crowdstrike_base_url(),
+            proxy: None,
+            connect_timeout_secs: default_connect_timeout_secs(),
+            request_timeout_secs: default_request_timeout_secs(),
+            max_retries: default_max_retries(),
+            llm_api_secret: None,
+        }
+    }
 }
 
 impl PAGIConfig {
-    /// Loads configuration from `.env` (if present) and environment variables.
+    /// Loads configuration from, in increasing precedence: the built-in
+    /// defaults, the TOML config file at `PAGI_CONFIG_PATH` (default
+    /// [`DEFAULT_CONFIG_PATH`], written out with the defaults if it doesn't
+    /// exist yet), and finally `.env`/environment variables.
     ///
-    /// # Panics
-    /// Panics if `OPENROUTER_API_KEY` is missing. This enforces secure
-    /// initialization at startup.
-    pub fn load() -> PAGIConfig {
+    /// Returns [`PagiError::UnknownClient`] if `active_client` doesn't name a
+    /// configured client, or [`PagiError::MissingApiKey`] if no layer ends up
+    /// supplying an API key for it, rather than panicking, so embedding
+    /// services can surface the problem however they see fit instead of
+    /// crashing at startup.
+    pub fn load() -> Result<PAGIConfig, PagiError> {
         // Load `.env` if available; ignore error so production env-only works.
         let _ = dotenvy::dotenv();
 
-        let openrouter_api_key =
-            env::var("OPENROUTER_API_KEY").expect("Missing required env var: OPENROUTER_API_KEY");
+        let path = env::var("PAGI_CONFIG_PATH").unwrap_or_else(|_| DEFAULT_CONFIG_PATH.to_string());
 
-        let default_model = env::var("OPENROUTER_DEFAULT_MODEL")
-            .unwrap_or_else(|_| "openai/gpt-4o-mini".to_string());
+        let mut config = if Path::new(&path).exists() {
+            Self::from_file(&path)?
+        } else {
+            let config = PAGIConfig::default();
+            config.write_to_file(&path)?;
+            config
+        };
 
-        let jira_api_token = env::var("JIRA_API_TOKEN").unwrap_or_default();
-        let jira_base_url =
-            env::var("JIRA_BASE_URL").unwrap_or_else(|_| "https://jira.example.com".to_string());
+        config.apply_env_overrides();
 
-        let crowdstrike_api_token = env::var("CROWDSTRIKE_API_TOKEN").unwrap_or_default();
-        let crowdstrike_base_url = env::var("CROWDSTRIKE_BASE_URL")
-            .unwrap_or_else(|_| "https://api.crowdstrike.com".to_string());
+        let active = config.active_client()?;
+        if active.common().api_key.is_empty() {
+            return Err(PagiError::MissingApiKey(active.name().to_string()));
+        }
 
-        PAGIConfig {
-            openrouter_api_key,
-            default_model,
-            jira_api_token,
-            jira_base_url,
-            crowdstrike_api_token,
-            crowdstrike_base_url,
+        Ok(config)
+    }
+
+    /// Deserializes a [`PAGIConfig`] from a TOML file at `path`.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<PAGIConfig, PagiError> {
+        let contents = fs::read_to_string(path)?;
+        Ok(toml::from_str(&contents)?)
+    }
+
+    /// Serializes this config to a TOML file at `path`, overwriting it if
+    /// present.
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> Result<(), PagiError> {
+        let contents = toml::to_string_pretty(self)?;
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Overrides file/default values with any environment variables that are
+    /// set, so env vars remain the highest-precedence layer.
+    fn apply_env_overrides(&mut self) {
+        if env::var("OPENROUTER_API_KEY").is_ok()
+            || env::var("OPENROUTER_API_BASE").is_ok()
+            || env::var("OPENROUTER_DEFAULT_MODEL").is_ok()
+        {
+            let common = &mut self.openrouter_config_mut().common;
+            if let Ok(v) = env::var("OPENROUTER_API_KEY") {
+                common.api_key = v;
+            }
+            if let Ok(v) = env::var("OPENROUTER_API_BASE") {
+                common.api_base = Some(v);
+            }
+            if let Ok(v) = env::var("OPENROUTER_DEFAULT_MODEL") {
+                common.models = Some(vec![v]);
+            }
+        }
+
+        if let Ok(v) = env::var("ACTIVE_LLM_CLIENT") {
+            self.active_client = v;
+        }
+
+        if let Ok(v) = env::var("JIRA_API_TOKEN") {
+            self.jira_api_token = v;
         }
+        if let Ok(v) = env::var("JIRA_BASE_URL") {
+            self.jira_base_url = v;
+        }
+
+        if let Ok(v) = env::var("CROWDSTRIKE_API_TOKEN") {
+            self.crowdstrike_api_token = v;
+        }
+        if let Ok(v) = env::var("CROWDSTRIKE_BASE_URL") {
+            self.crowdstrike_base_url = v;
+        }
+
+        // `PAGI_PROXY` wins if set; otherwise fall back to the proxy env vars
+        // reqwest itself understands, so ops doesn't need a PAGI-specific var.
+        if let Some(v) = env::var("PAGI_PROXY")
+            .ok()
+            .or_else(|| env::var("HTTPS_PROXY").ok())
+            .or_else(|| env::var("ALL_PROXY").ok())
+        {
+            self.proxy = Some(v);
+        }
+
+        if let Some(v) = env::var("PAGI_CONNECT_TIMEOUT_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+        {
+            self.connect_timeout_secs = v;
+        }
+        if let Some(v) = env::var("PAGI_REQUEST_TIMEOUT_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+        {
+            self.request_timeout_secs = v;
+        }
+        if let Some(v) = env::var("PAGI_MAX_RETRIES")
+            .ok()
+            .and_then(|v| v.parse().ok())
+        {
+            self.max_retries = v;
+        }
+
+        if let Ok(v) = env::var("LLM_API_SECRET") {
+            self.llm_api_secret = Some(v);
+        }
+    }
+
+    /// The configured OpenRouter backend, inserting an empty one if the file
+    /// didn't declare one, so a bare `OPENROUTER_API_KEY` still works without
+    /// requiring a `[[clients]]` entry in the config file.
+    fn openrouter_config_mut(&mut self) -> &mut OpenRouterConfig {
+        if !self
+            .clients
+            .iter()
+            .any(|c| matches!(c, ClientConfig::OpenRouter(_)))
+        {
+            self.clients
+                .push(ClientConfig::OpenRouter(OpenRouterConfig {
+                    common: CommonClientConfig {
+                        api_key: String::new(),
+                        api_base: None,
+                        models: None,
+                        extra: Default::default(),
+                    },
+                }));
+        }
+
+        self.clients
+            .iter_mut()
+            .find_map(|c| match c {
+                ClientConfig::OpenRouter(cfg) => Some(cfg),
+                _ => None,
+            })
+            .expect("just inserted above if missing")
+    }
+
+    /// The [`ClientConfig`] named by `active_client`.
+    pub fn active_client(&self) -> Result<&ClientConfig, PagiError> {
+        self.clients
+            .iter()
+            .find(|c| c.name() == self.active_client)
+            .ok_or_else(|| PagiError::UnknownClient(self.active_client.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    // `apply_env_overrides` reads process-global environment variables, so
+    // tests that set them must not run concurrently with each other.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn round_trips_through_a_toml_file() {
+        let path = std::env::temp_dir().join(format!("pagi-config-test-{}.toml", line!()));
+        let config = PAGIConfig::default();
+
+        config.write_to_file(&path).unwrap();
+        let loaded = PAGIConfig::from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.active_client, config.active_client);
+        assert_eq!(loaded.clients.len(), config.clients.len());
+        assert_eq!(loaded.connect_timeout_secs, config.connect_timeout_secs);
+    }
+
+    #[test]
+    fn env_vars_override_file_values() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        env::set_var("OPENROUTER_API_KEY", "env-key");
+        env::set_var("ACTIVE_LLM_CLIENT", "openrouter");
+        env::set_var("PAGI_MAX_RETRIES", "7");
+
+        let mut config = PAGIConfig::default();
+        config.apply_env_overrides();
+
+        env::remove_var("OPENROUTER_API_KEY");
+        env::remove_var("ACTIVE_LLM_CLIENT");
+        env::remove_var("PAGI_MAX_RETRIES");
+
+        assert_eq!(config.active_client, "openrouter");
+        assert_eq!(config.max_retries, 7);
+        assert_eq!(config.active_client().unwrap().common().api_key, "env-key");
+    }
+
+    #[test]
+    fn active_client_reports_the_real_unknown_name() {
+        let config = PAGIConfig {
+            active_client: "openai-typo".to_string(),
+            ..PAGIConfig::default()
+        };
+
+        let err = config.active_client().unwrap_err();
+        assert!(err.to_string().contains("openai-typo"));
     }
 }
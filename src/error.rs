@@ -0,0 +1,72 @@
+use thiserror::Error;
+
+/// Crate-wide error type for every external call this library makes.
+///
+/// Letting callers match on these variants (rather than propagating
+/// `reqwest::Error` or ad-hoc `String`s) is what makes this crate embeddable
+/// in services that need to tell "not configured" apart from "upstream is
+/// down" apart from "upstream sent us garbage".
+#[derive(Debug, Error)]
+pub enum PagiError {
+    /// A required environment variable (or config value standing in for
+    /// one, like an API token) was never set.
+    #[error("missing required configuration: {0}")]
+    MissingEnv(&'static str),
+
+    /// `active_client` doesn't name any configured [`crate::ClientConfig`].
+    #[error("no client named '{0}' is configured")]
+    UnknownClient(String),
+
+    /// The active client never got an API key from any config layer (file,
+    /// env, or `.env`).
+    #[error("missing API key for client '{0}'")]
+    MissingApiKey(String),
+
+    /// The request never made it to, or back from, the upstream API.
+    #[error("HTTP transport error: {0}")]
+    Transport(#[from] reqwest_middleware::Error),
+
+    /// A `reqwest` error raised outside the retrying middleware: building
+    /// the HTTP client or its proxy, or reading a response body.
+    #[error("HTTP transport error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// The upstream API responded, but with a non-2xx status.
+    #[error("upstream returned HTTP {status}: {body}")]
+    ApiStatus { status: u16, body: String },
+
+    /// The active backend doesn't support the requested operation.
+    #[error("{0}")]
+    Unsupported(String),
+
+    /// The upstream API responded with a 2xx status but a body this crate
+    /// couldn't parse.
+    #[error("failed to parse upstream response: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// Reading or writing the on-disk config file (see [`crate::PAGIConfig`])
+    /// failed.
+    #[error("config file I/O error: {0}")]
+    ConfigIo(#[from] std::io::Error),
+
+    /// The on-disk config file isn't valid TOML.
+    #[error("failed to parse config file: {0}")]
+    ConfigParse(#[from] toml::de::Error),
+
+    /// The in-memory config couldn't be serialized back to TOML when writing
+    /// a default config file.
+    #[error("failed to serialize config file: {0}")]
+    ConfigSerialize(#[from] toml::ser::Error),
+
+    /// Minting or verifying a gateway bearer token failed (see the
+    /// `server` module).
+    #[cfg(feature = "server")]
+    #[error("JWT error: {0}")]
+    Jwt(#[from] jsonwebtoken::errors::Error),
+
+    /// Installing the Prometheus recorder or the `tracing`/OpenTelemetry
+    /// pipeline failed (see the `metrics` module).
+    #[cfg(feature = "metrics")]
+    #[error("observability setup failed: {0}")]
+    Observability(String),
+}
@@ -3,12 +3,20 @@
 //! This crate centralizes:
 //! - secure config loading (via `.env` + environment variables)
 //! - external network I/O
-//! - LLM provider orchestration (OpenRouter)
+//! - LLM provider orchestration across multiple backends (OpenAI,
+//!   OpenRouter, Azure OpenAI, Anthropic, ...)
 
 pub mod api_clients;
+pub mod clients;
 pub mod config;
+pub mod error;
 pub mod llm_provider;
+pub mod metrics;
+#[cfg(feature = "server")]
+pub mod server;
 
 pub use api_clients::{CrowdstrikeClient, JiraClient};
+pub use clients::ClientConfig;
 pub use config::PAGIConfig;
+pub use error::PagiError;
 pub use llm_provider::LLMProvider;
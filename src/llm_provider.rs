@@ -1,96 +1,670 @@
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::clients::{anthropic, openai, openrouter, ClientConfig};
 use crate::config::PAGIConfig;
-use serde::{Deserialize, Serialize};
+use crate::error::PagiError;
+use futures_core::Stream;
+use futures_util::StreamExt;
+use http::Extensions;
+use reqwest::{Request, Response};
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware, Middleware, Next};
+use reqwest_retry::{
+    default_on_request_failure, default_on_request_success, policies::ExponentialBackoff,
+    RetryDecision, RetryPolicy, Retryable,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tracing::Instrument;
 
 /// LLM orchestration wrapper.
+///
+/// Dispatches to whichever [`ClientConfig`] is named by
+/// `config.active_client`, so callers get a single uniform
+/// `generate_response` regardless of which backend is actually configured.
 #[derive(Debug, Clone)]
 pub struct LLMProvider {
     pub config: PAGIConfig,
-    client: reqwest::Client,
+    client: ClientWithMiddleware,
 }
 
 impl LLMProvider {
     /// Creates a new provider with config loaded from the environment.
-    pub fn new() -> LLMProvider {
-        LLMProvider {
-            config: PAGIConfig::load(),
-            client: reqwest::Client::new(),
-        }
+    pub fn new() -> Result<LLMProvider, PagiError> {
+        let config = PAGIConfig::load()?;
+        let client = build_http_client(&config)?;
+        Ok(LLMProvider { config, client })
     }
 
-    /// Calls OpenRouter Chat Completions and returns the raw text response.
-    ///
-    /// Endpoint:
-    /// `https://openrouter.ai/api/v1/chat/completions`
+    /// As [`LLMProvider::new`], but also installs the global `tracing`
+    /// subscriber and Prometheus recorder, exporting spans via OTLP to
+    /// `otlp_endpoint`. Use this instead of `new()` when embedding this
+    /// crate as the top of a process's observability stack.
+    #[cfg(feature = "metrics")]
+    pub fn new_with_otel(otlp_endpoint: &str) -> Result<LLMProvider, PagiError> {
+        crate::metrics::init_tracing(Some(otlp_endpoint))?;
+        crate::metrics::install_recorder()?;
+        Self::new()
+    }
+
+    /// Calls the active backend's chat-completions endpoint (or, for
+    /// Anthropic, its Messages API) and returns the raw text response.
     pub async fn generate_response(
         &self,
         prompt: &str,
         system_prompt: &str,
         model: Option<&str>,
-    ) -> Result<String, reqwest::Error> {
-        let model = model.unwrap_or(&self.config.default_model);
-
-        let body = OpenRouterChatCompletionsRequest {
-            model,
-            messages: vec![
-                OpenRouterMessage {
-                    role: "system",
-                    content: system_prompt,
-                },
-                OpenRouterMessage {
+    ) -> Result<String, PagiError> {
+        let active = self.config.active_client()?;
+
+        if let ClientConfig::Anthropic(cfg) = active {
+            return self
+                .generate_response_anthropic(cfg, prompt, system_prompt, model)
+                .await;
+        }
+
+        let model = model
+            .map(str::to_string)
+            .unwrap_or_else(|| default_model(active));
+        let client_name = active.name();
+        let span = tracing::info_span!("llm_provider.generate_response", client = client_name, model = %model);
+
+        async move {
+            let body = ChatCompletionsRequest {
+                model: &model,
+                messages: vec![
+                    ChatMessage {
+                        role: "system",
+                        content: system_prompt,
+                    },
+                    ChatMessage {
+                        role: "user",
+                        content: prompt,
+                    },
+                ],
+                stream: None,
+            };
+
+            let started = Instant::now();
+            let result = async {
+                let resp = self
+                    .request(active, chat_completions_endpoint(active)?)
+                    .json(&body)
+                    .send()
+                    .await?;
+                parse_json_response::<ChatCompletionsResponse>(resp).await
+            }
+            .await;
+            crate::metrics::record_call(client_name, &model, started.elapsed(), result.is_ok());
+
+            let resp = result?;
+            if let Some(usage) = &resp.usage {
+                record_tokens(client_name, &model, usage);
+            }
+
+            Ok(resp
+                .choices
+                .into_iter()
+                .next()
+                .map(|c| c.message.content)
+                .unwrap_or_default())
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Calls Anthropic's native Messages API, whose request/response shape
+    /// differs from the OpenAI-compatible `chat/completions` backends.
+    async fn generate_response_anthropic(
+        &self,
+        cfg: &anthropic::AnthropicConfig,
+        prompt: &str,
+        system_prompt: &str,
+        model: Option<&str>,
+    ) -> Result<String, PagiError> {
+        let model = model
+            .map(str::to_string)
+            .unwrap_or_else(|| default_model_for(&cfg.common, "claude-3-5-sonnet-latest"));
+        let base = cfg
+            .common
+            .api_base
+            .as_deref()
+            .unwrap_or(anthropic::DEFAULT_API_BASE);
+        let span = tracing::info_span!("llm_provider.generate_response_anthropic", client = "anthropic", model = %model);
+
+        async move {
+            let body = AnthropicMessagesRequest {
+                model: &model,
+                system: system_prompt,
+                max_tokens: 4096,
+                messages: vec![AnthropicMessage {
                     role: "user",
                     content: prompt,
-                },
-            ],
-        };
-
-        let resp = self
-            .client
-            .post("https://openrouter.ai/api/v1/chat/completions")
-            .bearer_auth(&self.config.openrouter_api_key)
-            // Recommended by OpenRouter docs; harmless if unset.
-            .header("HTTP-Referer", "https://localhost")
-            .header("X-Title", "pagi-external-api-lib")
-            .json(&body)
-            .send()
-            .await?
-            .error_for_status()?
-            .json::<OpenRouterChatCompletionsResponse>()
-            .await?;
-
-        Ok(resp
-            .choices
-            .into_iter()
-            .next()
-            .map(|c| c.message.content)
-            .unwrap_or_default())
+                }],
+            };
+
+            let started = Instant::now();
+            let result = async {
+                let resp = self
+                    .client
+                    .post(format!("{base}/messages"))
+                    .header("x-api-key", &cfg.common.api_key)
+                    .header("anthropic-version", &cfg.anthropic_version)
+                    .json(&body)
+                    .send()
+                    .await?;
+                parse_json_response::<AnthropicMessagesResponse>(resp).await
+            }
+            .await;
+            crate::metrics::record_call("anthropic", &model, started.elapsed(), result.is_ok());
+
+            Ok(result?
+                .content
+                .into_iter()
+                .find_map(|block| block.text)
+                .unwrap_or_default())
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Calls the active backend's chat-completions endpoint with
+    /// `stream: true` and returns an async stream of incremental text deltas
+    /// as they arrive over SSE.
+    ///
+    /// Each yielded item is the `delta.content` of one `data: ` event. The
+    /// stream ends (without an error) when the backend sends `data: [DONE]`;
+    /// empty lines and `:`-prefixed keep-alive comments are skipped. Partial
+    /// lines are buffered across chunk boundaries since a single SSE event
+    /// can be split across TCP reads.
+    pub async fn generate_response_stream(
+        &self,
+        prompt: &str,
+        system_prompt: &str,
+        model: Option<&str>,
+    ) -> Result<impl Stream<Item = Result<String, PagiError>>, PagiError> {
+        let active = self.config.active_client()?;
+
+        if matches!(active, ClientConfig::Anthropic(_)) {
+            return Err(PagiError::Unsupported(
+                "anthropic does not support generate_response_stream yet; use generate_response"
+                    .to_string(),
+            ));
+        }
+
+        let model = model
+            .map(str::to_string)
+            .unwrap_or_else(|| default_model(active));
+        let client_name = active.name();
+        let span = tracing::info_span!("llm_provider.generate_response_stream", client = client_name, model = %model);
+
+        let resp = async {
+            let body = ChatCompletionsRequest {
+                model: &model,
+                messages: vec![
+                    ChatMessage {
+                        role: "system",
+                        content: system_prompt,
+                    },
+                    ChatMessage {
+                        role: "user",
+                        content: prompt,
+                    },
+                ],
+                stream: Some(true),
+            };
+
+            let started = Instant::now();
+            let resp = self
+                .request(active, chat_completions_endpoint(active)?)
+                .json(&body)
+                .send()
+                .await?;
+            let resp = error_for_status(resp).await;
+            crate::metrics::record_call(client_name, &model, started.elapsed(), resp.is_ok());
+            resp
+        }
+        .instrument(span)
+        .await?;
+
+        Ok(async_stream::try_stream! {
+            let mut bytes = resp.bytes_stream();
+            let mut lines = SseLineBuffer::default();
+
+            while let Some(chunk) = bytes.next().await {
+                for line in lines.push(&chunk.map_err(PagiError::from)?) {
+                    let Some(data) = line.strip_prefix("data: ") else {
+                        continue;
+                    };
+
+                    if data == "[DONE]" {
+                        return;
+                    }
+
+                    if let Ok(event) = serde_json::from_str::<ChatCompletionsStreamChunk>(data) {
+                        if let Some(choice) = event.choices.into_iter().next() {
+                            if let Some(content) = choice.delta.content {
+                                yield content;
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    /// Builds a `POST` request against `endpoint`, carrying whatever
+    /// headers the given backend needs for authentication.
+    ///
+    /// Only valid for the OpenAI-compatible backends (OpenAI, OpenRouter,
+    /// Azure OpenAI); Anthropic is handled separately since it authenticates
+    /// and shapes requests differently.
+    fn request(
+        &self,
+        active: &ClientConfig,
+        endpoint: String,
+    ) -> reqwest_middleware::RequestBuilder {
+        let req = self.client.post(endpoint);
+        match active {
+            ClientConfig::OpenAi(c) => req.bearer_auth(&c.common.api_key),
+            ClientConfig::OpenRouter(c) => req
+                .bearer_auth(&c.common.api_key)
+                // Recommended by OpenRouter docs; harmless if unset.
+                .header("HTTP-Referer", "https://localhost")
+                .header("X-Title", "pagi-external-api-lib"),
+            ClientConfig::AzureOpenAi(c) => req.header("api-key", &c.common.api_key),
+            ClientConfig::Anthropic(_) => unreachable!("anthropic uses its own request path"),
+        }
     }
 }
 
+/// The chat-completions endpoint URL for an OpenAI-compatible backend.
+///
+/// Returns [`PagiError::MissingEnv`] for an `azure-openai` client configured
+/// without `api_base`, rather than panicking on a reachable misconfiguration.
+fn chat_completions_endpoint(active: &ClientConfig) -> Result<String, PagiError> {
+    Ok(match active {
+        ClientConfig::OpenAi(c) => format!(
+            "{}/chat/completions",
+            c.common
+                .api_base
+                .as_deref()
+                .unwrap_or(openai::DEFAULT_API_BASE)
+        ),
+        ClientConfig::OpenRouter(c) => format!(
+            "{}/chat/completions",
+            c.common
+                .api_base
+                .as_deref()
+                .unwrap_or(openrouter::DEFAULT_API_BASE)
+        ),
+        ClientConfig::AzureOpenAi(c) => {
+            let base = c
+                .common
+                .api_base
+                .as_deref()
+                .ok_or(PagiError::MissingEnv("api_base of the azure-openai client"))?;
+            format!(
+                "{base}/openai/deployments/{}/chat/completions?api-version={}",
+                c.deployment, c.api_version
+            )
+        }
+        ClientConfig::Anthropic(_) => unreachable!("anthropic uses its own request path"),
+    })
+}
+
+/// The model to use when the caller didn't pick one: the backend's first
+/// configured model, or a sane built-in fallback. Azure routes by deployment
+/// name rather than model id, so it always uses its configured deployment.
+fn default_model(active: &ClientConfig) -> String {
+    match active {
+        ClientConfig::OpenAi(c) => default_model_for(&c.common, "gpt-4o-mini"),
+        ClientConfig::OpenRouter(c) => default_model_for(&c.common, "openai/gpt-4o-mini"),
+        ClientConfig::AzureOpenAi(c) => c.deployment.clone(),
+        ClientConfig::Anthropic(c) => default_model_for(&c.common, "claude-3-5-sonnet-latest"),
+    }
+}
+
+/// Builds the HTTP client shared by every backend call: a bare
+/// `reqwest::Client` configured with connect/request timeouts and an
+/// optional proxy, wrapped in retry middleware that retries 429/5xx
+/// responses with exponential backoff (honoring `Retry-After` when present).
+fn build_http_client(config: &PAGIConfig) -> Result<ClientWithMiddleware, PagiError> {
+    let mut builder = reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(config.connect_timeout_secs))
+        .timeout(Duration::from_secs(config.request_timeout_secs));
+
+    if let Some(proxy_url) = &config.proxy {
+        builder = builder.proxy(reqwest::Proxy::all(proxy_url)?);
+    }
+
+    let inner = builder.build()?;
+    let retry_policy = ExponentialBackoff::builder().build_with_max_retries(config.max_retries);
+
+    Ok(ClientBuilder::new(inner)
+        .with(RetryAfterMiddleware { retry_policy })
+        .build())
+}
+
+/// Like [`reqwest_retry::RetryTransientMiddleware`], but a `Retry-After`
+/// header on a transient response (delay-seconds or an HTTP-date) overrides
+/// the backoff policy's own computed wait, since that's what the upstream
+/// actually asked for. Falls back to the policy's schedule when the header
+/// is absent or unparseable; the policy alone still governs when to give up
+/// (`max_retries`).
+struct RetryAfterMiddleware {
+    retry_policy: ExponentialBackoff,
+}
+
+#[async_trait::async_trait]
+impl Middleware for RetryAfterMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let start_time = SystemTime::now();
+        let mut n_past_retries = 0;
+
+        loop {
+            let duplicate_request = req.try_clone().ok_or_else(|| {
+                reqwest_middleware::Error::Middleware(anyhow::anyhow!(
+                    "request body is not cloneable, cannot be retried"
+                ))
+            })?;
+
+            let result = next.clone().run(duplicate_request, extensions).await;
+
+            let retryable = match &result {
+                Ok(resp) => default_on_request_success(resp),
+                Err(err) => default_on_request_failure(err),
+            };
+            if retryable != Some(Retryable::Transient) {
+                return result;
+            }
+
+            let wait = match self.retry_policy.should_retry(start_time, n_past_retries) {
+                RetryDecision::DoNotRetry => return result,
+                RetryDecision::Retry { execute_after } => result
+                    .as_ref()
+                    .ok()
+                    .and_then(retry_after)
+                    .unwrap_or_else(|| {
+                        execute_after
+                            .duration_since(SystemTime::now())
+                            .unwrap_or_default()
+                    }),
+            };
+
+            tokio::time::sleep(wait).await;
+            n_past_retries += 1;
+        }
+    }
+}
+
+/// Parses a response's `Retry-After` header, as either delay-seconds or an
+/// HTTP-date, into a wait duration from now.
+fn retry_after(resp: &Response) -> Option<Duration> {
+    let value = resp
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?;
+
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let at: SystemTime = httpdate::parse_http_date(value).ok()?;
+    Some(at.duration_since(SystemTime::now()).unwrap_or_default())
+}
+
+/// Returns `resp` unchanged if it's a 2xx, otherwise reads the body and
+/// returns [`PagiError::ApiStatus`] carrying both the status and the body so
+/// callers can see exactly what the upstream API complained about.
+async fn error_for_status(resp: reqwest::Response) -> Result<reqwest::Response, PagiError> {
+    let status = resp.status();
+    if status.is_success() {
+        return Ok(resp);
+    }
+
+    let body = resp.text().await.unwrap_or_default();
+    Err(PagiError::ApiStatus {
+        status: status.as_u16(),
+        body,
+    })
+}
+
+/// Checks the response status via [`error_for_status`], then deserializes
+/// the body as `T`, reporting malformed JSON as [`PagiError::Json`] instead
+/// of the less specific error `reqwest::Response::json` would give.
+async fn parse_json_response<T: DeserializeOwned>(resp: reqwest::Response) -> Result<T, PagiError> {
+    let resp = error_for_status(resp).await?;
+    let bytes = resp.bytes().await?;
+    Ok(serde_json::from_slice(&bytes)?)
+}
+
+/// Incrementally splits raw SSE bytes into complete lines as they arrive.
+///
+/// Buffers whatever trailing bytes haven't seen a `\n` yet, including a
+/// UTF-8 codepoint split across two chunks, and only decodes a line once all
+/// of its bytes are in hand. `\n` is never part of a multi-byte UTF-8
+/// sequence, so splitting on it and decoding each resulting line
+/// independently is always safe.
+#[derive(Debug, Default)]
+struct SseLineBuffer {
+    buf: Vec<u8>,
+}
+
+impl SseLineBuffer {
+    /// Feeds in the next chunk of bytes, returning every line it completes
+    /// (decoded, with the trailing `\r\n`/`\n` stripped), skipping blank
+    /// lines and `:`-prefixed keep-alive comments.
+    fn push(&mut self, chunk: &[u8]) -> Vec<String> {
+        self.buf.extend_from_slice(chunk);
+
+        let mut lines = Vec::new();
+        while let Some(newline) = self.buf.iter().position(|&b| b == b'\n') {
+            let line_bytes: Vec<u8> = self.buf.drain(..=newline).collect();
+            let line = String::from_utf8_lossy(&line_bytes)
+                .trim_end_matches(['\r', '\n'])
+                .to_string();
+
+            if !line.is_empty() && !line.starts_with(':') {
+                lines.push(line);
+            }
+        }
+        lines
+    }
+}
+
+fn default_model_for(common: &crate::clients::CommonClientConfig, fallback: &str) -> String {
+    common
+        .models
+        .as_ref()
+        .and_then(|models| models.first())
+        .cloned()
+        .unwrap_or_else(|| fallback.to_string())
+}
+
+/// Records prompt/completion token counts parsed from a chat-completions
+/// response's `usage` field, labeled by client and model. A no-op unless the
+/// `metrics` feature is enabled.
+fn record_tokens(client: &str, model: &str, usage: &Usage) {
+    #[cfg(feature = "metrics")]
+    {
+        metrics::counter!(
+            "pagi_tokens_total",
+            "client" => client.to_string(),
+            "model" => model.to_string(),
+            "kind" => "prompt",
+        )
+        .increment(usage.prompt_tokens);
+        metrics::counter!(
+            "pagi_tokens_total",
+            "client" => client.to_string(),
+            "model" => model.to_string(),
+            "kind" => "completion",
+        )
+        .increment(usage.completion_tokens);
+    }
+    #[cfg(not(feature = "metrics"))]
+    let _ = (client, model, usage);
+}
+
 #[derive(Debug, Serialize)]
-struct OpenRouterChatCompletionsRequest<'a> {
+struct ChatCompletionsRequest<'a> {
     model: &'a str,
-    messages: Vec<OpenRouterMessage<'a>>,
+    messages: Vec<ChatMessage<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stream: Option<bool>,
 }
 
 #[derive(Debug, Serialize)]
-struct OpenRouterMessage<'a> {
+struct ChatMessage<'a> {
     role: &'a str,
     content: &'a str,
 }
 
 #[derive(Debug, Deserialize)]
-struct OpenRouterChatCompletionsResponse {
+struct ChatCompletionsResponse {
     #[allow(dead_code)]
     id: Option<String>,
-    choices: Vec<OpenRouterChoice>,
+    choices: Vec<ChatCompletionsChoice>,
+    #[serde(default)]
+    usage: Option<Usage>,
+}
+
+/// Token accounting reported by OpenAI-compatible `chat/completions`
+/// responses (present on OpenRouter, OpenAI, and Azure OpenAI).
+#[derive(Debug, Deserialize)]
+struct Usage {
+    // Only read when the `metrics` feature is enabled (see `record_tokens`).
+    #[allow(dead_code)]
+    #[serde(default)]
+    prompt_tokens: u64,
+    #[allow(dead_code)]
+    #[serde(default)]
+    completion_tokens: u64,
 }
 
 #[derive(Debug, Deserialize)]
-struct OpenRouterChoice {
-    message: OpenRouterChoiceMessage,
+struct ChatCompletionsChoice {
+    message: ChatCompletionsMessage,
 }
 
 #[derive(Debug, Deserialize)]
-struct OpenRouterChoiceMessage {
+struct ChatCompletionsMessage {
     content: String,
 }
+
+/// Shape of a single SSE `data: ` event emitted while streaming.
+#[derive(Debug, Deserialize)]
+struct ChatCompletionsStreamChunk {
+    choices: Vec<ChatCompletionsStreamChoice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionsStreamChoice {
+    delta: ChatCompletionsStreamDelta,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionsStreamDelta {
+    content: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct AnthropicMessagesRequest<'a> {
+    model: &'a str,
+    system: &'a str,
+    max_tokens: u32,
+    messages: Vec<AnthropicMessage<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct AnthropicMessage<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicMessagesResponse {
+    content: Vec<AnthropicContentBlock>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicContentBlock {
+    text: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{retry_after, SseLineBuffer};
+    use std::time::Duration;
+
+    fn response_with_retry_after(value: &str) -> reqwest::Response {
+        let http_resp = http::Response::builder()
+            .header(reqwest::header::RETRY_AFTER, value)
+            .body(Vec::new())
+            .unwrap();
+        reqwest::Response::from(http_resp)
+    }
+
+    #[test]
+    fn retry_after_parses_delay_seconds() {
+        assert_eq!(
+            retry_after(&response_with_retry_after("30")),
+            Some(Duration::from_secs(30))
+        );
+    }
+
+    #[test]
+    fn retry_after_parses_an_http_date_in_the_future() {
+        let at = httpdate::fmt_http_date(std::time::SystemTime::now() + Duration::from_secs(60));
+        let wait = retry_after(&response_with_retry_after(&at)).unwrap();
+        // Allow slack for the time spent formatting/parsing the date above.
+        assert!(wait.as_secs() >= 55 && wait.as_secs() <= 60);
+    }
+
+    #[test]
+    fn retry_after_is_none_when_header_is_absent() {
+        let http_resp = http::Response::builder().body(Vec::new()).unwrap();
+        assert_eq!(retry_after(&reqwest::Response::from(http_resp)), None);
+    }
+
+    #[test]
+    fn yields_lines_split_across_chunks() {
+        let mut buf = SseLineBuffer::default();
+        assert_eq!(buf.push(b"data: hel"), Vec::<String>::new());
+        assert_eq!(
+            buf.push(b"lo\ndata: world\n"),
+            vec!["data: hello", "data: world"]
+        );
+    }
+
+    #[test]
+    fn reassembles_a_multibyte_char_split_across_chunks() {
+        // "café" is "caf" + 0xC3 0xA9; split the two-byte 'é' across chunks.
+        let bytes = b"data: caf\xc3\xa9\n".to_vec();
+        let mut buf = SseLineBuffer::default();
+        let mut lines = Vec::new();
+        for byte in bytes {
+            lines.extend(buf.push(&[byte]));
+        }
+        assert_eq!(lines, vec!["data: caf\u{e9}"]);
+    }
+
+    #[test]
+    fn skips_blank_lines_and_comments() {
+        let mut buf = SseLineBuffer::default();
+        let lines = buf.push(b"\n: keep-alive\ndata: hi\n");
+        assert_eq!(lines, vec!["data: hi"]);
+    }
+
+    #[test]
+    fn carries_over_incomplete_trailing_line() {
+        let mut buf = SseLineBuffer::default();
+        assert_eq!(buf.push(b"data: a\ndata: b"), vec!["data: a"]);
+        assert_eq!(buf.push(b"\n"), vec!["data: b"]);
+    }
+}
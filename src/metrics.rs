@@ -0,0 +1,122 @@
+//! Optional observability: a Prometheus `/metrics` scrape endpoint backing
+//! the counters/histograms recorded by [`crate::llm_provider`] and
+//! [`crate::api_clients`], plus an OpenTelemetry tracing exporter.
+//!
+//! The scrape endpoint and OTLP exporter are off by default so callers that
+//! don't need them pay no cost; they require the `metrics` feature. The
+//! [`record_call`] helper itself is always available so call sites don't
+//! need their own `#[cfg]`.
+
+use std::time::Duration;
+
+#[cfg(feature = "metrics")]
+use std::sync::OnceLock;
+
+#[cfg(feature = "metrics")]
+use axum::{routing::get, Router};
+#[cfg(feature = "metrics")]
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+#[cfg(feature = "metrics")]
+use opentelemetry::trace::TracerProvider as _;
+#[cfg(feature = "metrics")]
+use opentelemetry_otlp::WithExportConfig;
+#[cfg(feature = "metrics")]
+use tracing_subscriber::layer::SubscriberExt;
+#[cfg(feature = "metrics")]
+use tracing_subscriber::util::SubscriberInitExt;
+
+#[cfg(feature = "metrics")]
+use crate::error::PagiError;
+
+#[cfg(feature = "metrics")]
+static PROMETHEUS_HANDLE: OnceLock<PrometheusHandle> = OnceLock::new();
+
+/// Records a completed call to an external backend: one `pagi_requests_total`
+/// increment and one `pagi_request_duration_seconds` observation, labeled by
+/// client and `operation` (an LLM model id for [`crate::llm_provider`] calls,
+/// an action name like `create_issue` for [`crate::api_clients`] calls). A
+/// no-op unless the `metrics` feature is enabled.
+pub(crate) fn record_call(client: &str, operation: &str, elapsed: Duration, success: bool) {
+    #[cfg(feature = "metrics")]
+    {
+        let outcome = if success { "success" } else { "error" };
+        metrics::counter!(
+            "pagi_requests_total",
+            "client" => client.to_string(),
+            "operation" => operation.to_string(),
+            "outcome" => outcome,
+        )
+        .increment(1);
+        metrics::histogram!(
+            "pagi_request_duration_seconds",
+            "client" => client.to_string(),
+            "operation" => operation.to_string(),
+        )
+        .record(elapsed.as_secs_f64());
+    }
+    #[cfg(not(feature = "metrics"))]
+    let _ = (client, operation, elapsed, success);
+}
+
+/// Installs the process-wide Prometheus recorder backing [`router`]'s
+/// `/metrics` endpoint. Call once at startup, before any instrumented code
+/// runs; later calls are a no-op.
+#[cfg(feature = "metrics")]
+pub fn install_recorder() -> Result<(), PagiError> {
+    if PROMETHEUS_HANDLE.get().is_some() {
+        return Ok(());
+    }
+
+    let handle = PrometheusBuilder::new()
+        .install_recorder()
+        .map_err(|err| PagiError::Observability(err.to_string()))?;
+    let _ = PROMETHEUS_HANDLE.set(handle);
+    Ok(())
+}
+
+/// Builds a `GET /metrics` route serving the current Prometheus snapshot.
+/// Mount it alongside the `server` gateway's router, or serve it on its own
+/// port.
+#[cfg(feature = "metrics")]
+pub fn router() -> Router {
+    Router::new().route("/metrics", get(scrape))
+}
+
+#[cfg(feature = "metrics")]
+async fn scrape() -> String {
+    PROMETHEUS_HANDLE
+        .get()
+        .map(|handle| handle.render())
+        .unwrap_or_default()
+}
+
+/// Initializes the global `tracing` subscriber. When `otlp_endpoint` is
+/// `Some`, spans are additionally exported via OTLP to that collector;
+/// otherwise this just installs a plain `fmt` subscriber.
+#[cfg(feature = "metrics")]
+pub fn init_tracing(otlp_endpoint: Option<&str>) -> Result<(), PagiError> {
+    let registry = tracing_subscriber::registry().with(tracing_subscriber::fmt::layer());
+
+    let Some(endpoint) = otlp_endpoint else {
+        return registry
+            .try_init()
+            .map_err(|err| PagiError::Observability(err.to_string()));
+    };
+
+    let provider = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(endpoint),
+        )
+        .install_batch(opentelemetry_sdk::runtime::Tokio)
+        .map_err(|err| PagiError::Observability(err.to_string()))?;
+
+    let tracer = provider.tracer("pagi-external-api-lib");
+
+    registry
+        .with(tracing_opentelemetry::layer().with_tracer(tracer))
+        .try_init()
+        .map_err(|err| PagiError::Observability(err.to_string()))
+}
@@ -0,0 +1,91 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+
+use crate::error::PagiError;
+
+/// Claims carried by a gateway bearer token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Claims {
+    /// Identifies the caller the token was issued to.
+    pub sub: String,
+    /// Expiry, as Unix seconds.
+    pub exp: usize,
+}
+
+/// Mints an HS256 bearer token for `subject`, valid for `ttl` from now.
+pub fn mint_token(subject: &str, ttl: Duration, secret: &str) -> Result<String, PagiError> {
+    let exp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock is before the Unix epoch")
+        + ttl;
+
+    let claims = Claims {
+        sub: subject.to_string(),
+        exp: exp.as_secs() as usize,
+    };
+
+    Ok(encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(secret.as_bytes()),
+    )?)
+}
+
+/// Verifies an HS256 bearer token, returning its claims if it's both
+/// correctly signed and unexpired.
+pub fn verify_token(token: &str, secret: &str) -> Result<Claims, PagiError> {
+    let mut validation = Validation::default();
+    validation.leeway = 0;
+    Ok(decode::<Claims>(
+        token,
+        &DecodingKey::from_secret(secret.as_bytes()),
+        &validation,
+    )?
+    .claims)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mints_and_verifies_a_token() {
+        let token = mint_token("alice", Duration::from_secs(60), "secret").unwrap();
+        let claims = verify_token(&token, "secret").unwrap();
+        assert_eq!(claims.sub, "alice");
+    }
+
+    #[test]
+    fn rejects_a_token_signed_with_a_different_secret() {
+        let token = mint_token("alice", Duration::from_secs(60), "secret").unwrap();
+        assert!(verify_token(&token, "wrong-secret").is_err());
+    }
+
+    #[test]
+    fn rejects_an_expired_token() {
+        let exp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as usize
+            - 120;
+        let claims = Claims {
+            sub: "alice".to_string(),
+            exp,
+        };
+        let token = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(b"secret"),
+        )
+        .unwrap();
+
+        assert!(verify_token(&token, "secret").is_err());
+    }
+
+    #[test]
+    fn rejects_a_malformed_token() {
+        assert!(verify_token("not-a-jwt", "secret").is_err());
+    }
+}
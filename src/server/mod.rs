@@ -0,0 +1,107 @@
+//! Optional HTTP gateway that puts `LLMProvider` behind an authenticated
+//! endpoint, so other internal services can call LLMs without holding the
+//! upstream provider's API key themselves. Callers instead hold a short-lived
+//! bearer token minted with [`mint_token`].
+//!
+//! Requires the `server` feature.
+
+mod auth;
+
+pub use auth::{mint_token, verify_token, Claims};
+
+use std::sync::Arc;
+
+use axum::{
+    extract::State,
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::post,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::error::PagiError;
+use crate::llm_provider::LLMProvider;
+
+/// Shared state for the gateway router: the provider to dispatch to and the
+/// secret used to verify caller tokens.
+#[derive(Clone)]
+pub struct GatewayState {
+    provider: Arc<LLMProvider>,
+    secret: Arc<str>,
+}
+
+impl GatewayState {
+    pub fn new(provider: LLMProvider, secret: impl Into<Arc<str>>) -> Self {
+        Self {
+            provider: Arc::new(provider),
+            secret: secret.into(),
+        }
+    }
+}
+
+/// Builds the gateway's `POST /v1/chat/completions` route. Mount it under
+/// your own `axum::Router`, or serve it directly with `axum::serve`.
+pub fn router(state: GatewayState) -> Router {
+    Router::new()
+        .route("/v1/chat/completions", post(chat_completions))
+        .with_state(state)
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionsRequest {
+    prompt: String,
+    #[serde(default)]
+    system_prompt: String,
+    model: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ChatCompletionsResponse {
+    content: String,
+}
+
+async fn chat_completions(
+    State(state): State<GatewayState>,
+    headers: HeaderMap,
+    Json(req): Json<ChatCompletionsRequest>,
+) -> Result<Json<ChatCompletionsResponse>, GatewayError> {
+    let token = bearer_token(&headers).ok_or(GatewayError::Unauthorized)?;
+    verify_token(token, &state.secret).map_err(|_| GatewayError::Unauthorized)?;
+
+    let content = state
+        .provider
+        .generate_response(&req.prompt, &req.system_prompt, req.model.as_deref())
+        .await
+        .map_err(GatewayError::Provider)?;
+
+    Ok(Json(ChatCompletionsResponse { content }))
+}
+
+fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+}
+
+/// Failure modes of the gateway handler, mapped to HTTP status codes.
+#[derive(Debug)]
+enum GatewayError {
+    Unauthorized,
+    Provider(PagiError),
+}
+
+impl IntoResponse for GatewayError {
+    fn into_response(self) -> Response {
+        match self {
+            GatewayError::Unauthorized => {
+                (StatusCode::UNAUTHORIZED, "invalid or missing bearer token").into_response()
+            }
+            GatewayError::Provider(err) => {
+                tracing::error!(error = %err, "upstream provider call failed");
+                (StatusCode::BAD_GATEWAY, "upstream provider call failed").into_response()
+            }
+        }
+    }
+}